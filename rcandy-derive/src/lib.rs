@@ -0,0 +1,175 @@
+/*
+Companion proc-macro crate for rcandy. Provides `#[derive(Properties)]`, which turns an `Rc<RefCell<TProp>>`
+field annotated with `#[property(...)]` into a `get_*`/`set_*` accessor pair and a `Property<TProp>`-returning
+method -- the same wiring a hand-written `PropertyImplementation` for a `Dog` would need, generated instead of
+typed out at every call site.
+*/
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, GenericArgument, Ident, Path, PathArguments, Type};
+
+/// Parsed form of a single `#[property(...)]` attribute.
+#[derive(Default)]
+struct PropertyAttr
+{
+    get: bool,
+    set: bool,
+    name: Option<Ident>,
+    get_path: Option<Path>,
+    set_path: Option<Path>,
+}
+
+fn parse_property_attr(attr: &syn::Attribute) -> PropertyAttr
+{
+    let mut parsed = PropertyAttr::default();
+
+    let _ = attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("get") {
+            if meta.input.peek(syn::token::Eq) {
+                parsed.get_path = Some(meta.value()?.parse()?);
+            }
+            parsed.get = true;
+        } else if meta.path.is_ident("set") {
+            if meta.input.peek(syn::token::Eq) {
+                parsed.set_path = Some(meta.value()?.parse()?);
+            }
+            parsed.set = true;
+        } else if meta.path.is_ident("name") {
+            let name: syn::LitStr = meta.value()?.parse()?;
+            parsed.name = Some(format_ident!("{}", name.value()));
+        }
+        Ok(())
+    });
+
+    parsed
+}
+
+/// Pulls `TProp` out of a `Rc<RefCell<TProp>>` field type. Panics with a clear message if the field isn't
+/// shaped that way, since the generated accessors only handle a `RefCell` store behind an `Rc`.
+fn infer_prop_type(field_ty: &Type) -> Type
+{
+    let unwrap_one = |ty: &Type, wrapper: &str| -> Option<Type> {
+        let Type::Path(type_path) = ty else { return None };
+        let segment = type_path.path.segments.last()?;
+        if segment.ident != wrapper {
+            return None;
+        }
+        let PathArguments::AngleBracketed(args) = &segment.arguments else { return None };
+        args.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(inner) => Some(inner.clone()),
+            _ => None,
+        })
+    };
+
+    let inner = unwrap_one(field_ty, "Rc")
+        .unwrap_or_else(|| panic!("#[property(..)] fields must be of type Rc<RefCell<TProp>>"));
+    unwrap_one(&inner, "RefCell")
+        .unwrap_or_else(|| panic!("#[property(..)] fields must be of type Rc<RefCell<TProp>>"))
+}
+
+/// Derives a `get_*`/`set_*` accessor pair and a `Property<TProp>`-returning method for every
+/// `Rc<RefCell<TProp>>` field annotated with `#[property(get, set)]`, `#[property(get)]` (read-only), or
+/// `#[property(get = path, set = path)]` for custom accessors. `name = "..."` can be added to any of these
+/// to rename the `Property`-returning method; every annotation needs `get` or `get = path`, or the derive panics.
+///
+/// Mirrors the glib `#[derive(Properties)]` model: `TProp` is inferred from the field type and the
+/// `Into<Property<TProp>>` wiring is emitted for you, the same way a hand-written `PropertyImplementation`
+/// would be threaded through a struct's `new()`. A custom `get = path`/`set = path` must have the same
+/// `fn(this: Rc<Self>) -> Rc<RefCell<TProp>>` / `fn(this: Rc<Self>, TProp)` signature as the generated
+/// accessors, since both are handed to `PropertyImplementation::new` as the same function pointer types.
+#[proc_macro_derive(Properties, attributes(property))]
+pub fn derive_properties(input: TokenStream) -> TokenStream
+{
+    let input = parse_macro_input!(input as DeriveInput);
+    let struct_ident = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => panic!("#[derive(Properties)] only supports structs with named fields"),
+        },
+        _ => panic!("#[derive(Properties)] only supports structs"),
+    };
+
+    let mut accessors = Vec::new();
+
+    for field in fields.iter() {
+        let Some(attr) = field.attrs.iter().find(|a| a.path().is_ident("property")) else {
+            continue;
+        };
+        let parsed = parse_property_attr(attr);
+        if !parsed.get && parsed.get_path.is_none() {
+            panic!("#[property(..)] fields must specify `get` or `get = path`");
+        }
+
+        let field_ident = field.ident.as_ref().expect("named field");
+        let prop_ty = infer_prop_type(&field.ty);
+        let property_ident = parsed.name.clone().unwrap_or_else(|| field_ident.clone());
+
+        let get_ident = parsed.get_path.clone()
+            .map(|path| path.get_ident().cloned().expect("get path must be a single identifier"))
+            .unwrap_or_else(|| format_ident!("get_{}", field_ident));
+        let set_ident = parsed.set_path.clone()
+            .map(|path| path.get_ident().cloned().expect("set path must be a single identifier"))
+            .unwrap_or_else(|| format_ident!("set_{}", field_ident));
+
+        // `get_ident`/`set_ident` are generated as associated functions taking `this: Rc<Self>`, not `&self`
+        // methods, because they're handed to `PropertyImplementation::new` as the `Getter`/`Setter` function
+        // pointers (`fn(this: TStruct) -> Rc<S>` / `fn(this: TStruct, TProp)`), exactly like the hand-written
+        // `Dog::get_size`/`Dog::set_size` pair. `PropertyImplementation<TStruct, ..>: Into<Property<..>>`
+        // requires `TStruct: Clone + 'static`, which a borrowed `&self` can never satisfy -- `Rc<Self>` can.
+        let get_method = if parsed.get_path.is_none() {
+            Some(quote! {
+                fn #get_ident(this: ::std::rc::Rc<Self>) -> ::std::rc::Rc<::std::cell::RefCell<#prop_ty>> {
+                    this.#field_ident.to_owned()
+                }
+            })
+        } else {
+            None
+        };
+
+        let set_method = if parsed.set && parsed.set_path.is_none() {
+            Some(quote! {
+                fn #set_ident(this: ::std::rc::Rc<Self>, value: #prop_ty) {
+                    this.#field_ident.replace(value);
+                }
+            })
+        } else {
+            None
+        };
+
+        // Both branches return `rcandy::Property<TProp>` so a read-only field is exposed through the same type
+        // as a read-write one -- a computed property with no setter is exactly what
+        // `ComputedImplementation`/`Property::computed` already model for a field with no setter at all. Its
+        // `set` panics, so its subscribers are never notified.
+        let property_accessor = if parsed.set {
+            quote! {
+                pub fn #property_ident(self: &::std::rc::Rc<Self>) -> rcandy::Property<#prop_ty> {
+                    rcandy::PropertyImplementation::new(self.clone(), Self::#get_ident, Self::#set_ident).into()
+                }
+            }
+        } else {
+            quote! {
+                pub fn #property_ident(self: &::std::rc::Rc<Self>) -> rcandy::Property<#prop_ty> {
+                    let this = self.clone();
+                    rcandy::Property::computed(move || Self::#get_ident(this.clone()).borrow().clone(), None)
+                }
+            }
+        };
+
+        accessors.push(quote! {
+            #get_method
+            #set_method
+            #property_accessor
+        });
+    }
+
+    let expanded = quote! {
+        impl #struct_ident {
+            #(#accessors)*
+        }
+    };
+
+    expanded.into()
+}
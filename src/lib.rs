@@ -7,84 +7,327 @@ of the struct itself.
 */
 
 
-/*
-TODO:
-- "item" in the structs needs to be wrapped in some type of smart pointer to allow for shared references.
-- finish having PropertyImplementation Into Property (along with the sole Getter and Setter types).
-- unit tests
-- add prototypes of "get_if()" and "set_if()" to allow for conditional assignment and access (in which case
-  the get should return a Result)
-- Observer pattern with a NotifyPropertyChanged Trait along with and Event dictionary and subscribe/unsubscribe
-  methods.
-*/
-
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
 use std::rc::Rc;
+use std::sync::{Mutex, RwLock};
+use std::task::{Context, Poll, Waker};
+
+/// Re-exported so downstream crates only need to depend on `rcandy` (with the `derive` feature enabled) to get
+/// `#[derive(Properties)]`, rather than also depending on `rcandy-derive` directly.
+#[cfg(feature = "derive")]
+pub use rcandy_derive::Properties;
+
+/// Function pointer an owning struct provides to hand back the `Rc<S>` backing one of its properties.
+type Getter<TStruct, S> = fn(this: TStruct) -> Rc<S>;
+/// Function pointer an owning struct provides to commit a new value for one of its properties.
+type Setter<TStruct, TProp> = fn(this: TStruct, TProp);
+/// A boxed hook run with the old and new value just before a `set` is committed.
+type BeforeSetHook<TProp> = Box<dyn Fn(&TProp, &TProp)>;
+/// A boxed hook run with the new value just after a `set` is committed.
+type AfterSetHook<TProp> = Box<dyn Fn(&TProp)>;
+
+/// Abstracts over the container backing a property's value, mirroring glib's `PropertyGet`/`PropertySet`.
+///
+/// `Property<TProp>` hardcoding `Rc<RefCell<TProp>>` limited it to a single backing container and made every
+/// access panic on a borrow conflict. Implementing this for `Mutex`/`RwLock` as well lets a property swap in
+/// a container that blocks instead of panicking on contention, while `RefCell` remains the zero-overhead
+/// default. This doesn't make `Property` `Send`/`Sync`, whatever the store: `Getter` hands back an `Rc<S>`,
+/// `Property` holds its implementation as a `Box<dyn ImplProp<..>>` with no `Send`/`Sync` bound, and
+/// subscribers are kept in `Rc`/`Cell`/`RefCell`, so a property can't be shared across threads.
+/// The closure-based `get` also means callers never get handed a raw `Rc<RefCell<_>>` clone to hold onto, so
+/// a read can't silently bypass the notify-on-`set` pipeline the way the old `Rc`-returning `GetProp::get` did.
+pub trait PropertyStore<TProp>
+{
+    /// Runs `f` against the current value and returns its result, without exposing the backing container.
+    fn get<R>(&self, f: impl Fn(&TProp) -> R) -> R;
+
+    /// Replaces the current value.
+    fn set(&self, val: TProp);
+}
+
+impl<TProp> PropertyStore<TProp> for RefCell<TProp>
+{
+    fn get<R>(&self, f: impl Fn(&TProp) -> R) -> R
+    {
+        f(&self.borrow())
+    }
 
-/// Type used to get a value.
-type GET<TProp> = fn() -> Rc<RefCell<TProp>>;
-/// Type used to set a value.
-type SET<TProp> = fn(TProp);
+    fn set(&self, val: TProp)
+    {
+        self.replace(val);
+    }
+}
 
-/// 
-type GETTER<TStruct, TProp> = fn(this: TStruct) -> Rc<RefCell<TProp>>;
-/// 
-type SETTER<TStruct, TProp> = fn(this: TStruct, TProp);
+impl<TProp> PropertyStore<TProp> for Mutex<TProp>
+{
+    fn get<R>(&self, f: impl Fn(&TProp) -> R) -> R
+    {
+        f(&self.lock().expect("property mutex poisoned"))
+    }
+
+    fn set(&self, val: TProp)
+    {
+        *self.lock().expect("property mutex poisoned") = val;
+    }
+}
+
+impl<TProp> PropertyStore<TProp> for RwLock<TProp>
+{
+    fn get<R>(&self, f: impl Fn(&TProp) -> R) -> R
+    {
+        f(&self.read().expect("property rwlock poisoned"))
+    }
+
+    fn set(&self, val: TProp)
+    {
+        *self.write().expect("property rwlock poisoned") = val;
+    }
+}
+
+/// Uniquely identifies a subscription registered through [`NotifyPropertyChanged::subscribe`], so it can later
+/// be handed back to [`NotifyPropertyChanged::unsubscribe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+/// A boxed callback invoked with the new value of a property whenever it changes.
+type ChangeCallback<TProp> = Box<dyn Fn(&TProp)>;
+
+/// Trait for observing changes made to a property's value.
+///
+/// Implementors keep an internal event dictionary of subscribed callbacks, keyed by the [`SubscriptionId`]
+/// returned from `subscribe`, and invoke every registered callback with the new value once a mutation commits.
+pub trait NotifyPropertyChanged<TProp>
+{
+    /// Registers `callback` to be invoked with the new value every time the property changes, returning an id
+    /// that can be passed to [`unsubscribe`](NotifyPropertyChanged::unsubscribe) to stop receiving notifications.
+    fn subscribe(&self, callback: ChangeCallback<TProp>) -> SubscriptionId;
+
+    /// Like [`subscribe`](NotifyPropertyChanged::subscribe), but `callback` fires at most once: the
+    /// implementation removes it as soon as the next change notification starts, instead of keeping it
+    /// registered (and dead) for the remaining life of the property. Used by [`Property::changed`] and
+    /// [`Property::with_change_func`].
+    fn subscribe_once(&self, callback: ChangeCallback<TProp>) -> SubscriptionId;
+
+    /// Removes a previously registered subscription. Unknown ids are silently ignored.
+    fn unsubscribe(&self, id: SubscriptionId);
+}
+
+/// A single registered subscriber. `once` subscribers (registered through
+/// [`subscribe_once`](SubscriberRegistry::subscribe_once)) are removed from the registry by the `notify` that
+/// invokes them, so a one-shot callback doesn't sit dead in the list for the remaining life of the property.
+///
+/// `callback` is reference-counted rather than owned outright so [`notify`](SubscriberRegistry::notify) can
+/// clone the list of callbacks to run and drop its borrow of `subscribers` *before* invoking any of them --
+/// letting a callback freely call `subscribe`/`unsubscribe`/`subscribe_once` on the same registry (e.g. a
+/// self-cancelling one-shot handler) or trigger a nested `set()` without re-entering a borrowed `RefCell`.
+struct Subscriber<TProp>
+{
+    id: SubscriptionId,
+    callback: Rc<dyn Fn(&TProp)>,
+    once: bool,
+}
+
+/// Event dictionary of subscribed callbacks backing every [`NotifyPropertyChanged`] implementation.
+struct SubscriberRegistry<TProp>
+{
+    next_id: Cell<u64>,
+    subscribers: RefCell<Vec<Subscriber<TProp>>>
+}
+
+impl<TProp> SubscriberRegistry<TProp>
+{
+    fn new() -> Self
+    {
+        Self
+        {
+            next_id: Cell::new(0),
+            subscribers: RefCell::new(Vec::new())
+        }
+    }
+
+    fn subscribe(&self, callback: ChangeCallback<TProp>) -> SubscriptionId
+    {
+        self.push(callback, false)
+    }
+
+    /// Registers `callback` to fire on the next `notify` only, then removes itself -- used by
+    /// [`Property::changed`] and [`Property::with_change_func`] so awaiting/registering for a single change
+    /// doesn't leak a dead subscriber into the registry once it's fired.
+    fn subscribe_once(&self, callback: ChangeCallback<TProp>) -> SubscriptionId
+    {
+        self.push(callback, true)
+    }
+
+    fn push(&self, callback: ChangeCallback<TProp>, once: bool) -> SubscriptionId
+    {
+        let id = SubscriptionId(self.next_id.get());
+        self.next_id.set(id.0 + 1);
+        self.subscribers.borrow_mut().push(Subscriber { id, callback: Rc::from(callback), once });
+        id
+    }
+
+    fn unsubscribe(&self, id: SubscriptionId)
+    {
+        self.subscribers.borrow_mut().retain(|sub| sub.id != id);
+    }
+
+    /// Snapshots the subscribed callbacks, removes every `once` subscriber from the registry, then invokes each
+    /// snapshotted callback with the new value. The borrow of `subscribers` is dropped before calling any of
+    /// them, so a callback that subscribes, unsubscribes (including unsubscribing itself), or triggers a nested
+    /// `set` on this same property doesn't re-enter an already-borrowed `RefCell`, and a nested `set` can't
+    /// fire a `once` subscriber a second time.
+    fn notify(&self, val: &TProp)
+    {
+        let snapshot: Vec<Subscriber<TProp>> = self.subscribers.borrow().iter()
+            .map(|sub| Subscriber { id: sub.id, callback: sub.callback.clone(), once: sub.once })
+            .collect();
+        self.subscribers.borrow_mut().retain(|sub| !sub.once);
+
+        for sub in &snapshot
+        {
+            (sub.callback)(val);
+        }
+    }
+}
+
+/// Error returned when a [`GetProp::get_if`] guard or a [`SetProp::set_if`] predicate rejects the access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyError
+{
+    /// A `get_if` guard returned `false` for the current value.
+    GuardRejected,
+    /// A `set_if` predicate returned `false` for the current and proposed values.
+    ValidationRejected,
+}
+
+impl std::fmt::Display for PropertyError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            PropertyError::GuardRejected => write!(f, "property get_if guard rejected the read"),
+            PropertyError::ValidationRejected => write!(f, "property set_if predicate rejected the write"),
+        }
+    }
+}
+
+impl std::error::Error for PropertyError {}
 
 /// Trait for getting a property value without knowing the owning item's type.
-pub trait GetProp<TProp>
+pub trait GetProp<TProp, S = RefCell<TProp>>
 {
-    fn get(&self) -> Rc<RefCell<TProp>>;
+    /// Object-safe primitive behind [`get`](GetProp::get): runs `f` against the current value without handing
+    /// back the backing container. Takes `&dyn Fn` rather than `impl Fn(&TProp) -> R` so it stays callable
+    /// through the `Box<dyn ImplProp<..>>` that backs [`Property`] -- a generic method can't be part of a
+    /// trait object's vtable.
+    fn get_with(&self, f: &dyn Fn(&TProp));
+
+    /// Runs `f` against the current value and returns its result. Never hands back the backing `Rc`/cell, so
+    /// a caller can't stash a clone of it and mutate straight past `set`'s notify-and-hook pipeline the way
+    /// the old `Rc<S>`-returning `get` allowed.
+    fn get<R>(&self, f: impl Fn(&TProp) -> R) -> R
+        where Self: Sized
+    {
+        let out: RefCell<Option<R>> = RefCell::new(None);
+        self.get_with(&|val| *out.borrow_mut() = Some(f(val)));
+        out.into_inner().expect("get_with must invoke f exactly once")
+    }
+
+    /// Like [`get`](GetProp::get), but only succeeds if `guard` accepts the current value, letting callers
+    /// prototype conditional access without custom getter boilerplate.
+    fn get_if(&self, guard: impl Fn(&TProp) -> bool) -> Result<TProp, PropertyError>
+        where Self: Sized, TProp: Clone
+    {
+        let guard_passed = Cell::new(false);
+        let val = self.get(|val| {
+            if guard(val)
+            {
+                guard_passed.set(true);
+            }
+            val.clone()
+        });
+        if guard_passed.get()
+        {
+            Ok(val)
+        }
+        else
+        {
+            Err(PropertyError::GuardRejected)
+        }
+    }
 }
 
 /// Trait for getting a property value with knowing the owning item's type.
-pub trait GetProperty<TStruct, TProp>
+pub trait GetProperty<TStruct, TProp, S = RefCell<TProp>>
 {
-    fn get(&self, item: TStruct) -> Rc<RefCell<TProp>>;
+    fn get(&self, item: TStruct) -> Rc<S>;
 }
 
 /// Trait for setting a property value without knowing the owning item's type.
-pub trait SetProp<TProp> 
+pub trait SetProp<TProp, S = RefCell<TProp>> : GetProp<TProp, S>
 {
     fn set(&self, val: TProp);
+
+    /// Only commits `val` if `predicate(old, new)` passes, letting callers prototype conditional assignment
+    /// (e.g. range validation) without custom setter boilerplate.
+    fn set_if(&self, val: TProp, predicate: impl Fn(&TProp, &TProp) -> bool) -> Result<(), PropertyError>
+        where Self: Sized
+    {
+        let passes = self.get(|old| predicate(old, &val));
+        if passes
+        {
+            self.set(val);
+            Ok(())
+        }
+        else
+        {
+            Err(PropertyError::ValidationRejected)
+        }
+    }
 }
 
 /// Trait for setting a property value with knowing the owning item's type.
-pub trait SetProperty<TStruct, TProp> 
+pub trait SetProperty<TStruct, TProp>
 {
     fn set(&self, item: TStruct, val: TProp);
 }
 
 /// Trait for implementing a property without knowing the owning item's type.
-pub trait ImplProp<TProp> : GetProp<TProp> + SetProp<TProp> {}
+pub trait ImplProp<TProp, S = RefCell<TProp>> : GetProp<TProp, S> + SetProp<TProp, S> + NotifyPropertyChanged<TProp> {}
 
 /// Trait for implementing a property with knowing the owning item's type.
-pub trait ImplProperty<TStruct, TProp> : GetProperty<TStruct, TProp> + GetProperty<TStruct, TProp> {}
+pub trait ImplProperty<TStruct, TProp, S = RefCell<TProp>> : GetProperty<TStruct, TProp, S> + GetProperty<TStruct, TProp, S> {}
 
 /// Struct representing a property getter.
-struct PropertyGetter<TStruct, TProp>
+struct PropertyGetter<TStruct, TProp, S = RefCell<TProp>>
 {
     item: TStruct,
-    get_func: GETTER<TStruct, TProp>
+    get_func: Getter<TStruct, S>,
+    _prop: PhantomData<TProp>
 }
 
 /// Implementation of a property getter.
-impl<TStruct, TProp> PropertyGetter<TStruct, TProp>
+impl<TStruct, TProp, S> PropertyGetter<TStruct, TProp, S>
 {
-    pub fn new(item: TStruct, get_func: GETTER<TStruct, TProp>) -> Self 
+    pub fn new(item: TStruct, get_func: Getter<TStruct, S>) -> Self
     {
-        Self 
+        Self
         {
             item,
-            get_func
+            get_func,
+            _prop: PhantomData
         }
     }
 }
 
 /// Implementation of getting the property value from the owning item.
-impl<TStruct, TProp> GetProperty<TStruct, TProp> for PropertyGetter<TStruct, TProp>
+impl<TStruct, TProp, S> GetProperty<TStruct, TProp, S> for PropertyGetter<TStruct, TProp, S>
 {
-    fn get(&self, item: TStruct) -> Rc<RefCell<TProp>>
+    fn get(&self, item: TStruct) -> Rc<S>
     {
         (self.get_func)(item)
     }
@@ -94,15 +337,15 @@ impl<TStruct, TProp> GetProperty<TStruct, TProp> for PropertyGetter<TStruct, TPr
 struct PropertySetter<TStruct, TProp>
 {
     item: TStruct,
-    pub set_func: SETTER<TStruct, TProp>
+    pub set_func: Setter<TStruct, TProp>
 }
 
 /// Implementation of a property setter.
 impl<TStruct, TProp> PropertySetter<TStruct, TProp>
 {
-    pub fn new(item: TStruct, set_func: SETTER<TStruct, TProp>) -> Self 
+    pub fn new(item: TStruct, set_func: Setter<TStruct, TProp>) -> Self
     {
-        Self 
+        Self
         {
             item,
             set_func
@@ -120,63 +363,339 @@ impl<TStruct, TProp> SetProperty<TStruct, TProp> for PropertySetter<TStruct, TPr
 }
 
 /// Struct representing a property implementing get and set behaviors from the owning item.
-struct PropertyImplementation<TStruct, TProp>
+///
+/// This is `pub` so that code generated by `#[derive(Properties)]` (in the companion `rcandy-derive` crate)
+/// can construct it directly in the annotated struct's own crate. Generic over the backing store `S` so a
+/// struct can expose a `Mutex`- or `RwLock`-backed property, not just `RefCell`; the resulting `Property` is
+/// still not `Send`/`Sync` (see [`PropertyStore`]).
+pub struct PropertyImplementation<TStruct, TProp, S = RefCell<TProp>>
 {
-    getter: PropertyGetter<TStruct, TProp>,
-    setter: PropertySetter<TStruct, TProp>
+    getter: PropertyGetter<TStruct, TProp, S>,
+    setter: PropertySetter<TStruct, TProp>,
+    subscribers: SubscriberRegistry<TProp>,
+    before_set: Option<BeforeSetHook<TProp>>,
+    after_set: Option<AfterSetHook<TProp>>
 }
 
-/// 
-impl<TStruct, TProp> Into<Property<TProp>> for PropertyImplementation<TStruct, TProp>
+/// Boxes a fully-wired `PropertyImplementation` up into the type-erased `Property<TProp, S>` that callers
+/// actually hold on to.
+impl<TStruct: Clone + 'static, TProp: Clone + 'static, S: PropertyStore<TProp> + 'static> From<PropertyImplementation<TStruct, TProp, S>> for Property<TProp, S>
 {
-    fn into(self) -> Property<TProp> {
-        todo!()
+    fn from(implementation: PropertyImplementation<TStruct, TProp, S>) -> Self {
+        Property { implementation: Box::new(implementation) }
     }
 }
 
+/// Constructs an implementation wired to the owning item's own getter/setter methods.
 ///
-impl<TStruct, TProp> PropertyImplementation<TStruct, TProp>
+/// `TStruct` only needs to be `Clone` (an `Rc`/`Arc` pointer to the owning item works fine) rather than
+/// `Copy`, so the item being shared doesn't have to be trivially copyable.
+impl<TStruct: Clone, TProp, S> PropertyImplementation<TStruct, TProp, S>
 {
-    pub fn new(item: TStruct, get_func: GETTER<TStruct, TProp>, set_func: SETTER<TStruct, TProp>) -> Self 
+    pub fn new(item: TStruct, get_func: Getter<TStruct, S>, set_func: Setter<TStruct, TProp>) -> Self
     {
-        Self 
+        Self
         {
-            getter: PropertyGetter::new(item, get_func),
-            setter: PropertySetter::new(item, set_func)
+            getter: PropertyGetter::new(item.clone(), get_func),
+            setter: PropertySetter::new(item, set_func),
+            subscribers: SubscriberRegistry::new(),
+            before_set: None,
+            after_set: None
         }
     }
+
+    /// Registers a hook run with the old and proposed values just before a `set` commits, e.g. for range
+    /// validation or logging. Replaces any hook set by a previous call.
+    pub fn before_set(mut self, hook: impl Fn(&TProp, &TProp) + 'static) -> Self
+    {
+        self.before_set = Some(Box::new(hook));
+        self
+    }
+
+    /// Registers a hook run with the new value just after a `set` commits, e.g. for side effects. Replaces
+    /// any hook set by a previous call.
+    pub fn after_set(mut self, hook: impl Fn(&TProp) + 'static) -> Self
+    {
+        self.after_set = Some(Box::new(hook));
+        self
+    }
 }
 
-///
-impl<TStruct, TProp> GetProperty<TStruct, TProp> for PropertyImplementation<TStruct, TProp>
+/// Implementation of getting the property value from the owning item.
+impl<TStruct, TProp, S> GetProperty<TStruct, TProp, S> for PropertyImplementation<TStruct, TProp, S>
 {
-    fn get(&self, item: TStruct) -> Rc<RefCell<TProp>> {
+    fn get(&self, item: TStruct) -> Rc<S> {
         self.getter.get(item)
     }
 }
 
-///
-impl<TStruct, TProp> SetProperty<TStruct, TProp> for PropertyImplementation<TStruct, TProp>
+/// Setting a value through the implementation runs `before_set` (if any), commits via the setter, then
+/// notifies every subscriber and runs `after_set` (if any) with the resulting value, reading it back out
+/// through the `PropertyStore` rather than assuming a `RefCell` to borrow.
+impl<TStruct: Clone, TProp: Clone, S: PropertyStore<TProp>> SetProperty<TStruct, TProp> for PropertyImplementation<TStruct, TProp, S>
 {
     fn set(&self, item: TStruct, val: TProp) {
-        self.setter.set(item, val)
+        if let Some(before) = &self.before_set
+        {
+            let old = self.getter.get(item.clone()).get(|v| v.clone());
+            before(&old, &val);
+        }
+        self.setter.set(item.clone(), val);
+        let new_val = self.getter.get(item).get(|v| v.clone());
+        self.subscribers.notify(&new_val);
+        if let Some(after) = &self.after_set
+        {
+            after(&new_val);
+        }
+    }
+}
+
+impl<TStruct, TProp, S> NotifyPropertyChanged<TProp> for PropertyImplementation<TStruct, TProp, S>
+{
+    fn subscribe(&self, callback: ChangeCallback<TProp>) -> SubscriptionId {
+        self.subscribers.subscribe(callback)
+    }
+
+    fn subscribe_once(&self, callback: ChangeCallback<TProp>) -> SubscriptionId {
+        self.subscribers.subscribe_once(callback)
+    }
+
+    fn unsubscribe(&self, id: SubscriptionId) {
+        self.subscribers.unsubscribe(id);
+    }
+}
+
+/// Implementation of getting the property value without the caller re-supplying the owning item, by closing
+/// over the item captured at construction time and reading it through the `PropertyStore`, so this can't
+/// bypass the lock/borrow behavior `S` implements.
+impl<TStruct: Clone, TProp, S: PropertyStore<TProp>> GetProp<TProp, S> for PropertyImplementation<TStruct, TProp, S>
+{
+    fn get_with(&self, f: &dyn Fn(&TProp)) {
+        self.getter.get(self.getter.item.clone()).get(|val| f(val));
+    }
+}
+
+/// Implementation of setting the property value without the caller re-supplying the owning item, running
+/// the same hook-and-notify sequence as [`SetProperty::set`].
+impl<TStruct: Clone, TProp: Clone, S: PropertyStore<TProp>> SetProp<TProp, S> for PropertyImplementation<TStruct, TProp, S>
+{
+    fn set(&self, val: TProp) {
+        SetProperty::set(self, self.setter.item.clone(), val)
+    }
+}
+
+impl<TStruct: Clone, TProp: Clone, S: PropertyStore<TProp>> ImplProp<TProp, S> for PropertyImplementation<TStruct, TProp, S> {}
+
+/// A property implementation with no backing field at all -- the gdnative `Property<T>` placeholder made
+/// concrete. `get_fn`/`set_fn` are plain closures (typically closing over clones of a struct's *other*
+/// properties) rather than methods on an owning item, since there's no stored value for an item to own.
+/// A read-only computed property simply has no `set_fn`.
+pub struct ComputedImplementation<TProp>
+{
+    get_fn: Box<dyn Fn() -> TProp>,
+    set_fn: Option<Box<dyn Fn(TProp)>>,
+    subscribers: SubscriberRegistry<TProp>
+}
+
+impl<TProp> ComputedImplementation<TProp>
+{
+    pub fn new(get_fn: impl Fn() -> TProp + 'static, set_fn: Option<Box<dyn Fn(TProp)>>) -> Self
+    {
+        Self
+        {
+            get_fn: Box::new(get_fn),
+            set_fn,
+            subscribers: SubscriberRegistry::new()
+        }
+    }
+}
+
+/// Evaluates the getter closure on demand and runs `f` against the freshly computed value, since there's no
+/// existing backing cell to read from.
+impl<TProp> GetProp<TProp> for ComputedImplementation<TProp>
+{
+    fn get_with(&self, f: &dyn Fn(&TProp)) {
+        f(&(self.get_fn)());
     }
 }
 
-struct Property<TProp> 
+/// Runs the setter closure, if one was supplied, and re-evaluates the getter to notify subscribers with the
+/// resulting value. Panics if called on a read-only computed property.
+impl<TProp> SetProp<TProp> for ComputedImplementation<TProp>
 {
-    implementation: Box<dyn ImplProp<TProp>>
+    fn set(&self, val: TProp) {
+        let set_fn = self.set_fn.as_ref().expect("computed property has no setter");
+        set_fn(val);
+        self.subscribers.notify(&(self.get_fn)());
+    }
 }
 
-impl<TProp> Property<TProp>
+impl<TProp> NotifyPropertyChanged<TProp> for ComputedImplementation<TProp>
 {
+    fn subscribe(&self, callback: ChangeCallback<TProp>) -> SubscriptionId {
+        self.subscribers.subscribe(callback)
+    }
 
+    fn subscribe_once(&self, callback: ChangeCallback<TProp>) -> SubscriptionId {
+        self.subscribers.subscribe_once(callback)
+    }
+
+    fn unsubscribe(&self, id: SubscriptionId) {
+        self.subscribers.unsubscribe(id);
+    }
 }
 
-impl<TProp> GetProp<TProp> for Property<TProp>
+impl<TProp> ImplProp<TProp> for ComputedImplementation<TProp> {}
+
+/// Boxes a computed implementation up into the type-erased `Property<TProp>` the same way a field-backed
+/// `PropertyImplementation` would be.
+impl<TProp: 'static> From<ComputedImplementation<TProp>> for Property<TProp>
 {
-    fn get(&self) -> Rc<RefCell<TProp>> {
-        todo!()
+    fn from(implementation: ComputedImplementation<TProp>) -> Self {
+        Property { implementation: Box::new(implementation) }
+    }
+}
+
+/// Shared state behind a [`Changed`] future: the value delivered by the one-shot subscriber, and the waker
+/// to rouse once it arrives.
+struct ChangedState<TProp>
+{
+    value: Option<TProp>,
+    waker: Option<Waker>,
+}
+
+/// Future returned by [`Property::changed`], resolving with the property's new value the next time
+/// [`set`](SetProp::set) runs on it.
+pub struct Changed<TProp>
+{
+    state: Rc<RefCell<ChangedState<TProp>>>,
+    id: SubscriptionId,
+}
+
+impl<TProp> Changed<TProp>
+{
+    /// The [`SubscriptionId`] of the one-shot subscriber backing this future, in case the caller wants to
+    /// [`unsubscribe`](NotifyPropertyChanged::unsubscribe) it manually (e.g. the future is about to be
+    /// dropped before it resolves and the caller wants to tear it down right away rather than waiting for
+    /// the next change to sweep it out).
+    pub fn id(&self) -> SubscriptionId
+    {
+        self.id
+    }
+}
+
+impl<TProp: Clone> Future for Changed<TProp>
+{
+    type Output = TProp;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<TProp>
+    {
+        let mut state = self.state.borrow_mut();
+        if let Some(value) = state.value.take()
+        {
+            Poll::Ready(value)
+        }
+        else
+        {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+/// Public-facing, type-erased property handle. Holds no knowledge of the owning struct's type, so it can be
+/// returned from trait methods and stored as a plain field. Generic over the backing store `S`, defaulting to
+/// the zero-overhead `RefCell` but accepting `Mutex`/`RwLock` (via `PropertyStore`) so the container blocks
+/// instead of panicking under contention. `Property` itself is not `Send`/`Sync` for any store -- see
+/// [`PropertyStore`] for why.
+pub struct Property<TProp, S = RefCell<TProp>>
+{
+    implementation: Box<dyn ImplProp<TProp, S>>
+}
+
+impl<TProp, S> Property<TProp, S>
+{
+
+}
+
+impl<TProp: Clone + 'static, S> Property<TProp, S>
+{
+    /// Returns a future that resolves with this property's new value the next time it's set, so async code
+    /// can `await` a change instead of polling `get()` in a loop.
+    pub fn changed(&self) -> Changed<TProp>
+    {
+        let state = Rc::new(RefCell::new(ChangedState { value: None, waker: None }));
+
+        let state_for_callback = state.clone();
+        let id = self.subscribe_once(Box::new(move |val: &TProp| {
+            let mut state = state_for_callback.borrow_mut();
+            if state.value.is_none()
+            {
+                state.value = Some(val.clone());
+                if let Some(waker) = state.waker.take()
+                {
+                    waker.wake();
+                }
+            }
+        }));
+
+        Changed { state, id }
+    }
+
+    /// Registers `f` to run once, with the property's new value, the next time it's set -- a callback-style
+    /// alternative to [`changed`](Property::changed) for non-async callers. Returns the underlying
+    /// [`SubscriptionId`] so the caller can cancel it via [`unsubscribe`](NotifyPropertyChanged::unsubscribe)
+    /// before it fires, though the subscriber is also removed automatically the moment it does fire.
+    pub fn with_change_func(&self, f: impl FnOnce(TProp) + 'static) -> SubscriptionId
+    {
+        let f = RefCell::new(Some(f));
+        self.subscribe_once(Box::new(move |val: &TProp| {
+            if let Some(f) = f.borrow_mut().take()
+            {
+                f(val.clone());
+            }
+        }))
+    }
+}
+
+impl<TProp: 'static> Property<TProp>
+{
+    /// Builds a property with no backing field, computed from `get_fn` on every read. `set_fn` is `None` for
+    /// a read-only computed property (e.g. `volume` derived from `size`), or `Some` to allow writing through
+    /// to whatever other properties `set_fn` closes over.
+    pub fn computed(get_fn: impl Fn() -> TProp + 'static, set_fn: Option<Box<dyn Fn(TProp)>>) -> Self
+    {
+        ComputedImplementation::new(get_fn, set_fn).into()
+    }
+}
+
+impl<TProp, S> GetProp<TProp, S> for Property<TProp, S>
+{
+    fn get_with(&self, f: &dyn Fn(&TProp)) {
+        self.implementation.get_with(f);
+    }
+}
+
+impl<TProp, S> SetProp<TProp, S> for Property<TProp, S>
+{
+    fn set(&self, val: TProp) {
+        self.implementation.set(val);
+    }
+}
+
+/// A property forwards subscribe/unsubscribe calls straight to its implementation's event dictionary.
+impl<TProp, S> NotifyPropertyChanged<TProp> for Property<TProp, S>
+{
+    fn subscribe(&self, callback: ChangeCallback<TProp>) -> SubscriptionId {
+        self.implementation.subscribe(callback)
+    }
+
+    fn subscribe_once(&self, callback: ChangeCallback<TProp>) -> SubscriptionId {
+        self.implementation.subscribe_once(callback)
+    }
+
+    fn unsubscribe(&self, id: SubscriptionId) {
+        self.implementation.unsubscribe(id);
     }
 }
 
@@ -185,49 +704,97 @@ impl<TProp> GetProp<TProp> for Property<TProp>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::task::Wake;
+
+    struct TestWaker(AtomicBool);
+
+    impl Wake for TestWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
 
-    trait HasSize 
+    trait HasSize
     {
-        fn size() -> Property<u64>;
+        fn size(self: &Rc<Self>) -> Property<u64>;
     }
 
     struct Dog
     {
         _dog_size: Rc<RefCell<u64>>,
-        dog_size: Property<u64>,
     }
 
     impl Dog
     {
-        pub fn new(&self, size: u64) -> Self 
+        pub fn new(size: u64) -> Rc<Self>
         {
-            Self 
+            Rc::new(Self
             {
-                dog_size: PropertyImplementation::new
-                (
-                    self,
-                    Dog::get_size,
-                    Dog::set_size
-                ).into(),
                 _dog_size: Rc::new(RefCell::new(size))
-            }
+            })
         }
 
-        fn get_size(&self) -> Rc<RefCell<u64>> 
+        fn get_size(this: Rc<Dog>) -> Rc<RefCell<u64>>
         {
-            self._dog_size.to_owned()
+            this._dog_size.to_owned()
         }
 
-        fn set_size(&self, value: u64)
+        fn set_size(this: Rc<Dog>, value: u64)
         {
-            self._dog_size.replace(value);
+            this._dog_size.replace(value);
         }
 
     }
 
     impl HasSize for Dog {
-        fn size() -> Property<u64> {
-            todo!()
+        fn size(self: &Rc<Self>) -> Property<u64> {
+            PropertyImplementation::new(self.clone(), Dog::get_size, Dog::set_size).into()
+        }
+    }
+
+    impl Dog {
+        /// Read-only property computed from `size`, with no `_volume` field backing it.
+        fn volume(self: &Rc<Self>) -> Property<u64> {
+            let size = self.size();
+            Property::computed(move || {
+                let s = size.get(|s| *s);
+                s * s * s
+            }, None)
+        }
+    }
+
+    /// Mirrors `Dog`, but backed by `Mutex` instead of `RefCell`, to exercise a `Property<_, Mutex<_>>`
+    /// through the same `PropertyImplementation::new` wiring `PropertyStore` was built to support.
+    struct Thermostat
+    {
+        _temp: Rc<Mutex<u64>>,
+    }
+
+    impl Thermostat
+    {
+        fn new(temp: u64) -> Rc<Self>
+        {
+            Rc::new(Self { _temp: Rc::new(Mutex::new(temp)) })
+        }
+
+        fn get_temp(this: Rc<Thermostat>) -> Rc<Mutex<u64>>
+        {
+            this._temp.to_owned()
+        }
+
+        fn set_temp(this: Rc<Thermostat>, value: u64)
+        {
+            *this._temp.lock().expect("property mutex poisoned") = value;
+        }
+
+        fn temp(self: &Rc<Self>) -> Property<u64, Mutex<u64>> {
+            PropertyImplementation::new(self.clone(), Thermostat::get_temp, Thermostat::set_temp).into()
         }
     }
 
@@ -235,4 +802,207 @@ mod tests {
     fn it_works() {
         assert_eq!(4, 4);
     }
+
+    #[test]
+    fn subscribe_and_unsubscribe_control_which_callbacks_notify_reaches() {
+        let registry: SubscriberRegistry<u64> = SubscriberRegistry::new();
+        let calls = Rc::new(RefCell::new(Vec::new()));
+
+        let calls_for_a = calls.clone();
+        let id_a = registry.subscribe(Box::new(move |v: &u64| calls_for_a.borrow_mut().push(("a", *v))));
+        let calls_for_b = calls.clone();
+        registry.subscribe(Box::new(move |v: &u64| calls_for_b.borrow_mut().push(("b", *v))));
+
+        registry.notify(&1);
+        assert_eq!(*calls.borrow(), vec![("a", 1), ("b", 1)]);
+
+        registry.unsubscribe(id_a);
+        registry.notify(&2);
+        assert_eq!(*calls.borrow(), vec![("a", 1), ("b", 1), ("b", 2)]);
+    }
+
+    #[test]
+    fn a_callback_can_unsubscribe_itself_from_inside_notify() {
+        // The natural way to implement a self-cancelling subscriber with this API: capture your own
+        // SubscriptionId (known only once `subscribe` returns) via an `Rc<Cell<_>>`, and call `unsubscribe`
+        // on receipt. This must not panic with "already borrowed" from inside `notify`.
+        let registry: Rc<SubscriberRegistry<u64>> = Rc::new(SubscriberRegistry::new());
+        let calls = Rc::new(RefCell::new(0));
+
+        let own_id: Rc<Cell<Option<SubscriptionId>>> = Rc::new(Cell::new(None));
+        let own_id_for_callback = own_id.clone();
+        let registry_for_callback = registry.clone();
+        let calls_for_callback = calls.clone();
+        let id = registry.subscribe(Box::new(move |_: &u64| {
+            *calls_for_callback.borrow_mut() += 1;
+            if let Some(id) = own_id_for_callback.get()
+            {
+                registry_for_callback.unsubscribe(id);
+            }
+        }));
+        own_id.set(Some(id));
+
+        registry.notify(&1);
+        assert_eq!(*calls.borrow(), 1);
+
+        registry.notify(&2);
+        assert_eq!(*calls.borrow(), 1, "the callback unsubscribed itself and shouldn't fire again");
+    }
+
+    #[test]
+    fn property_set_notifies_subscribers() {
+        let dog = Dog::new(4);
+        let size = dog.size();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        let seen_for_callback = seen.clone();
+        size.subscribe(Box::new(move |new_size: &u64| {
+            seen_for_callback.borrow_mut().push(*new_size);
+        }));
+
+        size.set(7);
+
+        assert_eq!(*seen.borrow(), vec![7]);
+    }
+
+    #[test]
+    fn computed_property_derives_from_backing_field() {
+        let dog = Dog::new(2);
+        let volume = dog.volume();
+
+        assert_eq!(volume.get(|v| *v), 8);
+
+        dog.size().set(3);
+
+        assert_eq!(volume.get(|v| *v), 27);
+    }
+
+    #[test]
+    fn get_if_and_set_if_guard_reads_and_writes() {
+        let dog = Dog::new(4);
+        let size = dog.size();
+
+        assert!(size.get_if(|s| *s == 4).is_ok());
+        assert_eq!(size.get_if(|s| *s > 100).unwrap_err(), PropertyError::GuardRejected);
+
+        assert!(size.set_if(5, |old, new| new > old).is_ok());
+        assert_eq!(size.get(|v| *v), 5);
+
+        assert_eq!(size.set_if(1, |old, new| new > old).unwrap_err(), PropertyError::ValidationRejected);
+        assert_eq!(size.get(|v| *v), 5);
+    }
+
+    #[test]
+    fn mutex_backed_property_gets_sets_and_notifies() {
+        let thermostat = Thermostat::new(68);
+        let temp = thermostat.temp();
+        let seen = Rc::new(RefCell::new(Vec::new()));
+
+        let seen_for_callback = seen.clone();
+        temp.subscribe(Box::new(move |new_temp: &u64| {
+            seen_for_callback.borrow_mut().push(*new_temp);
+        }));
+
+        assert_eq!(temp.get(|v| *v), 68);
+
+        temp.set(72);
+        assert_eq!(temp.get(|v| *v), 72);
+        assert_eq!(*seen.borrow(), vec![72]);
+
+        assert!(temp.get_if(|v| *v > 70).is_ok());
+        assert!(temp.set_if(75, |old, new| new > old).is_ok());
+        assert_eq!(temp.get(|v| *v), 75);
+    }
+
+    #[test]
+    fn before_and_after_set_hooks_run_around_the_commit() {
+        let dog = Dog::new(4);
+        let seen_before = Rc::new(RefCell::new(None));
+        let seen_after = Rc::new(RefCell::new(None));
+
+        let seen_before_for_hook = seen_before.clone();
+        let seen_after_for_hook = seen_after.clone();
+        let size: Property<u64> = PropertyImplementation::new(dog.clone(), Dog::get_size, Dog::set_size)
+            .before_set(move |old, new| *seen_before_for_hook.borrow_mut() = Some((*old, *new)))
+            .after_set(move |new| *seen_after_for_hook.borrow_mut() = Some(*new))
+            .into();
+
+        size.set(9);
+
+        assert_eq!(*seen_before.borrow(), Some((4, 9)));
+        assert_eq!(*seen_after.borrow(), Some(9));
+    }
+
+    #[test]
+    fn changed_future_resolves_on_next_set() {
+        let dog = Dog::new(4);
+        let size = dog.size();
+        let mut changed = size.changed();
+
+        let test_waker = Arc::new(TestWaker(AtomicBool::new(false)));
+        let waker = Waker::from(test_waker.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(Pin::new(&mut changed).poll(&mut cx), Poll::Pending);
+        assert!(!test_waker.0.load(Ordering::SeqCst));
+
+        size.set(9);
+        assert!(test_waker.0.load(Ordering::SeqCst));
+
+        match Pin::new(&mut changed).poll(&mut cx) {
+            Poll::Ready(val) => assert_eq!(val, 9),
+            Poll::Pending => panic!("expected changed() to resolve after set"),
+        }
+    }
+
+    #[test]
+    fn with_change_func_fires_once_on_next_set() {
+        let dog = Dog::new(4);
+        let size = dog.size();
+        let calls = Rc::new(RefCell::new(Vec::new()));
+
+        let calls_for_callback = calls.clone();
+        size.with_change_func(move |val| calls_for_callback.borrow_mut().push(val));
+
+        size.set(7);
+        size.set(8);
+
+        assert_eq!(*calls.borrow(), vec![7]);
+    }
+
+    #[test]
+    fn subscribe_once_is_swept_from_the_registry_after_firing() {
+        let registry: SubscriberRegistry<u64> = SubscriberRegistry::new();
+
+        registry.subscribe_once(Box::new(|_| {}));
+        assert_eq!(registry.subscribers.borrow().len(), 1);
+
+        registry.notify(&1);
+        assert_eq!(registry.subscribers.borrow().len(), 0, "one-shot subscriber should be removed once it fires");
+
+        registry.notify(&2);
+        assert_eq!(registry.subscribers.borrow().len(), 0, "a notify with no subscribers shouldn't grow the list");
+    }
+
+    #[test]
+    fn set_still_commits_after_changed_and_with_change_func_fired() {
+        let dog = Dog::new(4);
+        let size = dog.size();
+
+        let mut changed = size.changed();
+        size.with_change_func(|_| {});
+
+        let test_waker = Arc::new(TestWaker(AtomicBool::new(false)));
+        let waker = Waker::from(test_waker.clone());
+        let mut cx = Context::from_waker(&waker);
+
+        size.set(7);
+        let _ = Pin::new(&mut changed).poll(&mut cx);
+
+        // A further `set` after both one-shot subscribers have already fired should behave exactly like a
+        // property with no subscribers left -- see `subscribe_once_is_swept_from_the_registry_after_firing`
+        // for the actual proof that they were removed rather than just not firing again.
+        size.set(8);
+        assert_eq!(size.get(|v| *v), 8);
+    }
 }
@@ -0,0 +1,54 @@
+#![cfg(feature = "derive")]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use rcandy::{GetProp, NotifyPropertyChanged, Properties, SetProp};
+
+#[derive(Properties)]
+struct Cat
+{
+    #[property(get, set, name = "lives")]
+    lives_field: Rc<RefCell<u64>>,
+
+    #[property(get, name = "nine_lives")]
+    nine_lives_field: Rc<RefCell<u64>>,
+}
+
+fn new_cat(lives: u64) -> Rc<Cat>
+{
+    Rc::new(Cat { lives_field: Rc::new(RefCell::new(lives)), nine_lives_field: Rc::new(RefCell::new(9)) })
+}
+
+#[test]
+fn derived_accessor_gets_and_sets() {
+    let cat = new_cat(9);
+
+    let lives = cat.lives();
+    assert_eq!(lives.get(|v| *v), 9);
+
+    lives.set(7);
+    assert_eq!(lives.get(|v| *v), 7);
+}
+
+#[test]
+fn derived_accessor_notifies_subscribers() {
+    let cat = new_cat(9);
+    let lives = cat.lives();
+
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let seen_for_callback = seen.clone();
+    lives.subscribe(Box::new(move |val: &u64| seen_for_callback.borrow_mut().push(*val)));
+
+    lives.set(3);
+
+    assert_eq!(*seen.borrow(), vec![3]);
+}
+
+#[test]
+fn derived_read_only_accessor_returns_a_property() {
+    let cat = new_cat(9);
+    let nine_lives: rcandy::Property<u64> = cat.nine_lives();
+
+    assert_eq!(nine_lives.get(|v| *v), 9);
+}